@@ -1,15 +1,58 @@
 //! Geometric types for 2D lattice-shaped puzzles.
 
+pub mod tri;
+
+use std::convert::TryFrom;
 use std::ops::{Add, Index, IndexMut, Mul, Neg, Range, Sub};
 
 /// A two-dimensional lattice point.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Point(pub i32, pub i32);
 
+impl Point {
+    /// Converts to a pair of `f64`.
+    #[inline]
+    pub fn to_f64(self) -> (f64, f64) {
+        (f64::from(self.0), f64::from(self.1))
+    }
+
+    /// Converts to a pair of `f32`.
+    #[inline]
+    pub fn to_f32(self) -> (f32, f32) {
+        (self.0 as f32, self.1 as f32)
+    }
+
+    /// Converts to a pair of `i16`, or `None` if either component overflows.
+    #[inline]
+    pub fn try_cast_i16(self) -> Option<(i16, i16)> {
+        Some((i16::try_from(self.0).ok()?, i16::try_from(self.1).ok()?))
+    }
+}
+
 /// A size of a rectangle.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Size(pub i32, pub i32);
 
+impl Size {
+    /// Converts to a pair of `f64`.
+    #[inline]
+    pub fn to_f64(self) -> (f64, f64) {
+        (f64::from(self.0), f64::from(self.1))
+    }
+
+    /// Converts to a pair of `f32`.
+    #[inline]
+    pub fn to_f32(self) -> (f32, f32) {
+        (self.0 as f32, self.1 as f32)
+    }
+
+    /// Converts to a pair of `i16`, or `None` if either component overflows.
+    #[inline]
+    pub fn try_cast_i16(self) -> Option<(i16, i16)> {
+        Some((i16::try_from(self.0).ok()?, i16::try_from(self.1).ok()?))
+    }
+}
+
 /// A difference between two `Point`s.
 ///
 /// `Point(y0, x0)` - `Point(y1, x1) == `Move(y0 - y1, x0 - x1)`
@@ -103,6 +146,156 @@ impl Mul<i32> for Move {
     }
 }
 
+/// Returns `floor(sqrt(n))` for `n >= 0`, via Newton's method.
+#[inline]
+pub(crate) fn isqrt(n: i64) -> i64 {
+    if n == 0 {
+        return 0;
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+impl Move {
+    /// Returns the dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Move) -> i32 {
+        self.0 * other.0 + self.1 * other.1
+    }
+
+    /// Returns the 2D perpendicular dot product (cross product) of `self`
+    /// and `other`, useful for orientation/turn tests.
+    #[inline]
+    pub fn perp_dot(self, other: Move) -> i32 {
+        self.0 * other.1 - self.1 * other.0
+    }
+
+    /// Returns the Manhattan (L1) norm: `|y| + |x|`.
+    #[inline]
+    pub fn manhattan_norm(self) -> i32 {
+        self.0.abs() + self.1.abs()
+    }
+
+    /// Returns the Chebyshev (L∞) norm: `max(|y|, |x|)`, matching
+    /// `MOVE_ALL_ADJACENTS` adjacency.
+    #[inline]
+    pub fn chebyshev_norm(self) -> i32 {
+        self.0.abs().max(self.1.abs())
+    }
+
+    /// Returns the Euclidean (L2) norm, rounded to the nearest integer.
+    #[inline]
+    pub fn euclidean_norm(self) -> u32 {
+        let y = i64::from(self.0);
+        let x = i64::from(self.1);
+        let n = y * y + x * x;
+        let floor = isqrt(n);
+        let rounded = if (floor + 1) * (floor + 1) - n <= n - floor * floor {
+            floor + 1
+        } else {
+            floor
+        };
+        rounded as u32
+    }
+
+    /// Returns a `Move` with each component's sign.
+    #[inline]
+    pub fn signum(self) -> Move {
+        Move(self.0.signum(), self.1.signum())
+    }
+
+    /// Returns a `Move` with each component's absolute value.
+    #[inline]
+    pub fn abs(self) -> Move {
+        Move(self.0.abs(), self.1.abs())
+    }
+
+    /// Returns the componentwise minimum of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Move) -> Move {
+        Move(self.0.min(other.0), self.1.min(other.1))
+    }
+
+    /// Returns the componentwise maximum of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Move) -> Move {
+        Move(self.0.max(other.0), self.1.max(other.1))
+    }
+
+    /// Returns the component of `self` along `axis`.
+    #[inline]
+    pub fn axis(self, axis: Axis) -> i32 {
+        match axis {
+            Axis::Horizontal => self.1,
+            Axis::Vertical => self.0,
+        }
+    }
+
+    /// Returns a `Move` of `value` along `axis` and `0` on the other axis.
+    #[inline]
+    pub fn on_axis(axis: Axis, value: i32) -> Move {
+        match axis {
+            Axis::Horizontal => Move(0, value),
+            Axis::Vertical => Move(value, 0),
+        }
+    }
+
+    /// Converts to a pair of `f64`.
+    #[inline]
+    pub fn to_f64(self) -> (f64, f64) {
+        (f64::from(self.0), f64::from(self.1))
+    }
+
+    /// Converts to a pair of `f32`.
+    #[inline]
+    pub fn to_f32(self) -> (f32, f32) {
+        (self.0 as f32, self.1 as f32)
+    }
+
+    /// Converts to a pair of `i16`, or `None` if either component overflows.
+    #[inline]
+    pub fn try_cast_i16(self) -> Option<(i16, i16)> {
+        Some((i16::try_from(self.0).ok()?, i16::try_from(self.1).ok()?))
+    }
+
+    /// Returns the integer projection of `self` onto `onto`, or the zero
+    /// `Move` if `onto` is the zero vector.
+    #[inline]
+    pub fn project_on(self, onto: Move) -> Move {
+        let denom = onto.dot(onto);
+        if denom == 0 {
+            Move(0, 0)
+        } else {
+            onto * (self.dot(onto) / denom)
+        }
+    }
+}
+
+/// An axis of the lattice.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Axis {
+    /// The left-right axis (the column direction).
+    Horizontal,
+    /// The up-down axis (the row direction).
+    Vertical,
+}
+
+/// A position along an `Axis` relative to a containing rectangle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Alignment {
+    /// Flush with the rectangle's near edge.
+    Start,
+    /// Centered within the rectangle, rounding down.
+    Center,
+    /// Flush with the rectangle's far edge.
+    End,
+}
+
 /// A 0-degree `Rotation` to the left (counterclockwise).
 pub const ROT_CCW0: Rotation = Rotation(1, 0, 0, 1);
 
@@ -183,6 +376,15 @@ pub trait Geom {
     #[inline]
     fn size(&self) -> Size;
 
+    /// Returns the rectangle's origin.
+    ///
+    /// Defaults to `Point(0, 0)`, matching every `Geom` implementor except
+    /// `Rect`, which may be anchored anywhere on the lattice.
+    #[inline]
+    fn origin(&self) -> Point {
+        Point(0, 0)
+    }
+
     /// Returns the number of the rectangle's rows.
     #[inline]
     fn row(&self) -> i32 {
@@ -205,14 +407,16 @@ pub trait Geom {
     #[inline]
     fn contains(&self, p: Point) -> bool {
         let size = self.size();
-        0 <= p.0 && p.0 < size.0 && 0 <= p.1 && p.1 < size.1
+        let origin = self.origin();
+        origin.0 <= p.0 && p.0 < origin.0 + size.0 && origin.1 <= p.1 && p.1 < origin.1 + size.1
     }
 
     /// Convert a point to a corresponding cell ID.
     #[inline]
     fn point_to_cellid(&self, p: Point) -> CellId {
         if self.contains(p) {
-            CellId::new((p.0 * self.column() + p.1 + 1) as usize)
+            let rel = p - self.origin();
+            CellId::new((rel.0 * self.column() + rel.1 + 1) as usize)
         } else {
             CELL_ID_OUTSIDE
         }
@@ -225,7 +429,11 @@ pub trait Geom {
             OUTSIDE_POINT
         } else {
             let idx = id.id() - 1;
-            Point((idx as i32) / self.column(), (idx as i32) % self.column())
+            let origin = self.origin();
+            Point(
+                (idx as i32) / self.column() + origin.0,
+                (idx as i32) % self.column() + origin.1,
+            )
         }
     }
 
@@ -234,12 +442,14 @@ pub trait Geom {
     fn points(&self) -> Points {
         if self.row() > 0 && self.column() > 0 {
             Points {
-                point: Some(Point(0, 0)),
+                point: Some(self.origin()),
+                origin: self.origin(),
                 size: self.size(),
             }
         } else {
             Points {
                 point: None,
+                origin: self.origin(),
                 size: self.size(),
             }
         }
@@ -248,26 +458,72 @@ pub trait Geom {
     /// Returns an iterator iterating all points in the row.
     #[inline]
     fn points_in_row(&self, row: i32) -> PointsInRow {
+        let origin = self.origin();
         PointsInRow {
             row: row,
-            columns: 0..self.column(),
+            columns: origin.1..(origin.1 + self.column()),
         }
     }
 
     /// Returns an iterator iterating all points in the column.
     #[inline]
     fn points_in_column(&self, column: i32) -> PointsInColumn {
+        let origin = self.origin();
         PointsInColumn {
             column: column,
-            rows: 0..self.row(),
+            rows: origin.0..(origin.0 + self.row()),
         }
     }
+
+    /// Rotates/flips `p` by `rot` about the center of the rectangle.
+    ///
+    /// `p` is first made relative to `self.origin()`, then converted to a
+    /// doubled, centered coordinate so the rectangle's center sits at the
+    /// origin without needing fractions, then `rot` is applied and the
+    /// result is re-anchored into the rectangle's rotated size (90°/270°
+    /// swap the row and column extents) and translated back by the origin.
+    #[inline]
+    fn transform_point(&self, p: Point, rot: Rotation) -> Point {
+        let size = self.size();
+        let origin = self.origin();
+        let new_size = {
+            let m = rot * Move(size.0, size.1);
+            Size(m.0.abs(), m.1.abs())
+        };
+        let rel = p - origin;
+        let centered = Move(2 * rel.0 - (size.0 - 1), 2 * rel.1 - (size.1 - 1));
+        let rotated = rot * centered;
+        Point(
+            (rotated.0 + (new_size.0 - 1)) / 2 + origin.0,
+            (rotated.1 + (new_size.1 - 1)) / 2 + origin.1,
+        )
+    }
+
+    /// Returns the top-left `Point` where a rectangle of `size` should sit
+    /// inside `self` so it is aligned `h` horizontally and `v` vertically.
+    #[inline]
+    fn align(&self, size: Size, h: Alignment, v: Alignment) -> Point {
+        let self_size = self.size();
+        let origin = self.origin();
+        let row = match v {
+            Alignment::Start => origin.0,
+            Alignment::Center => origin.0 + (self_size.0 - size.0) / 2,
+            Alignment::End => origin.0 + self_size.0 - size.0,
+        };
+        let column = match h {
+            Alignment::Start => origin.1,
+            Alignment::Center => origin.1 + (self_size.1 - size.1) / 2,
+            Alignment::End => origin.1 + self_size.1 - size.1,
+        };
+        Point(row, column)
+    }
 }
 
 /// An iterator iterating all points in the rectangle.
 #[derive(Copy, Clone, Debug)]
 pub struct Points {
     point: Option<Point>,
+    origin: Point,
     size: Size,
 }
 
@@ -280,10 +536,10 @@ impl Iterator for Points {
             let mut next = cur;
             let mut end = false;
             next.1 += 1;
-            if next.1 >= self.size.1 {
+            if next.1 >= self.origin.1 + self.size.1 {
                 next.0 += 1;
-                next.1 = 0;
-                if next.0 >= self.size.0 {
+                next.1 = self.origin.1;
+                if next.0 >= self.origin.0 + self.size.0 {
                     end = true;
                 }
             }
@@ -375,6 +631,40 @@ impl<T> Geom for Table<T> {
     }
 }
 
+impl<T> Table<T>
+where
+    T: Clone,
+{
+    /// Returns a new table rotated/flipped by `rot`.
+    ///
+    /// The new table's size is `self`'s size rotated by `rot` (90°/270°
+    /// swap the row and column extents), and each destination cell is
+    /// filled from the source cell that `transform_point` maps to it.
+    pub fn transformed(&self, rot: Rotation) -> Table<T> {
+        let size = self.size();
+        let new_size = {
+            let m = rot * Move(size.0, size.1);
+            Size(m.0.abs(), m.1.abs())
+        };
+        // The inverse of an orthogonal rotation/flip matrix is its
+        // transpose.
+        let inv = Rotation(rot.0, rot.2, rot.1, rot.3);
+        let mut data = Vec::with_capacity((new_size.0 * new_size.1) as usize);
+        for row in 0..new_size.0 {
+            for column in 0..new_size.1 {
+                let centered = Move(2 * row - (new_size.0 - 1), 2 * column - (new_size.1 - 1));
+                let source_centered = inv * centered;
+                let source = Point(
+                    (source_centered.0 + (size.0 - 1)) / 2,
+                    (source_centered.1 + (size.1 - 1)) / 2,
+                );
+                data.push(self[source].clone());
+            }
+        }
+        Table::new(new_size, self.data[0].clone(), data)
+    }
+}
+
 impl<T> Index<Point> for Table<T> {
     type Output = T;
 
@@ -393,12 +683,112 @@ impl<T> IndexMut<Point> for Table<T> {
     }
 }
 
+/// A rectangle area anchored at an arbitrary `origin`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Rect {
+    origin: Point,
+    size: Size,
+}
+
+impl Rect {
+    /// Creates a new `Rect` with the given origin and size.
+    #[inline]
+    pub fn new(origin: Point, size: Size) -> Rect {
+        Rect {
+            origin: origin,
+            size: size,
+        }
+    }
+
+    /// Returns the point just past the rectangle's far corner, i.e.
+    /// `origin + size`.
+    #[inline]
+    fn far_corner(&self) -> Point {
+        Point(self.origin.0 + self.size.0, self.origin.1 + self.size.1)
+    }
+
+    /// Returns the overlapping area of `self` and `other`, or `None` if they
+    /// do not overlap.
+    #[inline]
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Point(
+            self.origin.0.max(other.origin.0),
+            self.origin.1.max(other.origin.1),
+        );
+        let self_far = self.far_corner();
+        let other_far = other.far_corner();
+        let max = Point(self_far.0.min(other_far.0), self_far.1.min(other_far.1));
+        if min.0 < max.0 && min.1 < max.1 {
+            Some(Rect::new(min, Size(max.0 - min.0, max.1 - min.1)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest `Rect` containing both `self` and `other`.
+    #[inline]
+    pub fn union(&self, other: &Rect) -> Rect {
+        let min = Point(
+            self.origin.0.min(other.origin.0),
+            self.origin.1.min(other.origin.1),
+        );
+        let self_far = self.far_corner();
+        let other_far = other.far_corner();
+        let max = Point(self_far.0.max(other_far.0), self_far.1.max(other_far.1));
+        Rect::new(min, Size(max.0 - min.0, max.1 - min.1))
+    }
+
+    /// Returns true if `self` fully contains `other`.
+    #[inline]
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        let self_far = self.far_corner();
+        let other_far = other.far_corner();
+        self.origin.0 <= other.origin.0
+            && self.origin.1 <= other.origin.1
+            && other_far.0 <= self_far.0
+            && other_far.1 <= self_far.1
+    }
+
+    /// Returns `self` translated by `mv`.
+    #[inline]
+    pub fn translate(&self, mv: Move) -> Rect {
+        Rect::new(self.origin + mv, self.size)
+    }
+
+    /// Returns `self` shrunk by `mv` on each side.
+    #[inline]
+    pub fn inset(&self, mv: Move) -> Rect {
+        Rect::new(
+            self.origin + mv,
+            Size(self.size.0 - mv.0 * 2, self.size.1 - mv.1 * 2),
+        )
+    }
+
+    /// Returns `self` grown by `mv` on each side.
+    #[inline]
+    pub fn outset(&self, mv: Move) -> Rect {
+        self.inset(-mv)
+    }
+}
+
+impl Geom for Rect {
+    #[inline]
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    #[inline]
+    fn origin(&self) -> Point {
+        self.origin
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    struct Rect(Size);
-    impl Geom for Rect {
+    struct TestRect(Size);
+    impl Geom for TestRect {
         fn size(&self) -> Size {
             self.0
         }
@@ -420,7 +810,7 @@ mod tests {
             Point(3, 1),
             Point(3, 2),
         ];
-        let rect = Rect(Size(4, 3));
+        let rect = TestRect(Size(4, 3));
         assert_eq!(&pts[..], &rect.points().collect::<Vec<_>>()[..]);
     }
 
@@ -454,4 +844,164 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn rect_intersection() {
+        let a = Rect::new(Point(0, 0), Size(4, 4));
+        let b = Rect::new(Point(2, 2), Size(4, 4));
+        assert_eq!(
+            Some(Rect::new(Point(2, 2), Size(2, 2))),
+            a.intersection(&b)
+        );
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+
+        let c = Rect::new(Point(10, 10), Size(2, 2));
+        assert_eq!(None, a.intersection(&c));
+
+        // Sharing only an edge is not an overlap.
+        let d = Rect::new(Point(4, 0), Size(4, 4));
+        assert_eq!(None, a.intersection(&d));
+    }
+
+    #[test]
+    fn rect_union() {
+        let a = Rect::new(Point(0, 0), Size(4, 4));
+        let b = Rect::new(Point(2, 2), Size(4, 4));
+        assert_eq!(Rect::new(Point(0, 0), Size(6, 6)), a.union(&b));
+        assert_eq!(a.union(&b), b.union(&a));
+    }
+
+    #[test]
+    fn rect_contains_rect() {
+        let outer = Rect::new(Point(0, 0), Size(10, 10));
+        let inner = Rect::new(Point(2, 2), Size(4, 4));
+        assert!(outer.contains_rect(&inner));
+        assert!(!inner.contains_rect(&outer));
+        assert!(outer.contains_rect(&outer));
+
+        let overflowing = Rect::new(Point(8, 8), Size(4, 4));
+        assert!(!outer.contains_rect(&overflowing));
+    }
+
+    #[test]
+    fn rect_translate() {
+        let rect = Rect::new(Point(1, 2), Size(3, 4));
+        assert_eq!(
+            Rect::new(Point(2, 0), Size(3, 4)),
+            rect.translate(Move(1, -2))
+        );
+    }
+
+    #[test]
+    fn rect_inset_outset() {
+        let rect = Rect::new(Point(0, 0), Size(10, 10));
+        let inset = rect.inset(Move(1, 2));
+        assert_eq!(Rect::new(Point(1, 2), Size(8, 6)), inset);
+        assert_eq!(rect, inset.outset(Move(1, 2)));
+    }
+
+    #[test]
+    fn move_dot_and_perp_dot() {
+        assert_eq!(11, Move(2, 3).dot(Move(1, 3)));
+        assert_eq!(3, Move(2, 3).perp_dot(Move(1, 3)));
+        assert_eq!(-3, Move(1, 3).perp_dot(Move(2, 3)));
+        assert_eq!(0, Move(2, 3).perp_dot(Move(2, 3)));
+    }
+
+    #[test]
+    fn move_norms() {
+        assert_eq!(7, Move(-3, 4).manhattan_norm());
+        assert_eq!(4, Move(-3, 4).chebyshev_norm());
+        assert_eq!(5, Move(-3, 4).euclidean_norm());
+        // Rounds to the nearest integer rather than truncating: sqrt(8) is
+        // about 2.83 and rounds up to 3, sqrt(2) is about 1.41 and rounds down.
+        assert_eq!(3, Move(2, 2).euclidean_norm());
+        assert_eq!(1, Move(1, 1).euclidean_norm());
+        assert_eq!(0, Move(0, 0).euclidean_norm());
+    }
+
+    #[test]
+    fn move_signum_abs_min_max() {
+        assert_eq!(Move(1, -1), Move(5, -5).signum());
+        assert_eq!(Move(0, -1), Move(0, -5).signum());
+        assert_eq!(Move(5, 5), Move(-5, 5).abs());
+        assert_eq!(Move(-2, 1), Move(-2, 3).min(Move(4, 1)));
+        assert_eq!(Move(4, 3), Move(-2, 3).max(Move(4, 1)));
+    }
+
+    #[test]
+    fn move_axis() {
+        assert_eq!(3, Move(2, 3).axis(Axis::Horizontal));
+        assert_eq!(2, Move(2, 3).axis(Axis::Vertical));
+        assert_eq!(Move(0, 3), Move::on_axis(Axis::Horizontal, 3));
+        assert_eq!(Move(3, 0), Move::on_axis(Axis::Vertical, 3));
+    }
+
+    #[test]
+    fn geom_align() {
+        let table = Table::new_empty(Size(10, 10), 0, 0);
+        assert_eq!(
+            Point(0, 0),
+            table.align(Size(4, 4), Alignment::Start, Alignment::Start)
+        );
+        assert_eq!(
+            Point(3, 3),
+            table.align(Size(4, 4), Alignment::Center, Alignment::Center)
+        );
+        assert_eq!(
+            Point(6, 6),
+            table.align(Size(4, 4), Alignment::End, Alignment::End)
+        );
+
+        // Aligning a sub-rectangle inside a `Rect` must be relative to the
+        // `Rect`'s own origin, not the lattice origin.
+        let rect = Rect::new(Point(5, 5), Size(10, 10));
+        assert_eq!(
+            Point(5, 5),
+            rect.align(Size(4, 4), Alignment::Start, Alignment::Start)
+        );
+        assert_eq!(
+            Point(11, 11),
+            rect.align(Size(4, 4), Alignment::End, Alignment::End)
+        );
+    }
+
+    #[test]
+    fn cast_to_float() {
+        assert_eq!((2.0, -3.0), Point(2, -3).to_f64());
+        assert_eq!((2.0, -3.0), Point(2, -3).to_f32());
+        assert_eq!((2.0, -3.0), Move(2, -3).to_f64());
+        assert_eq!((2.0, -3.0), Size(2, -3).to_f64());
+    }
+
+    #[test]
+    fn try_cast_i16() {
+        assert_eq!(Some((2, -3)), Point(2, -3).try_cast_i16());
+        assert_eq!(None, Point(i32::from(i16::MAX) + 1, 0).try_cast_i16());
+        assert_eq!(None, Move(0, i32::from(i16::MIN) - 1).try_cast_i16());
+        assert_eq!(Some((2, -3)), Size(2, -3).try_cast_i16());
+    }
+
+    #[test]
+    fn move_project_on() {
+        // Decomposing a diagonal move onto an axis.
+        assert_eq!(Move(0, 3), Move(2, 3).project_on(MOVE_RIGHT));
+        assert_eq!(Move(2, 0), Move(2, 3).project_on(MOVE_UP));
+        // Projecting onto the zero vector degrades to the zero vector.
+        assert_eq!(Move(0, 0), Move(2, 3).project_on(Move(0, 0)));
+    }
+
+    #[test]
+    fn transform_point_origin() {
+        // A table at the implicit origin: its own center maps to itself
+        // under a 180-degree rotation.
+        let table = Table::new_empty(Size(3, 3), 0, 0);
+        assert_eq!(Point(1, 1), table.transform_point(Point(1, 1), ROT_CCW180));
+
+        // A `Rect` anchored away from the origin must apply the same
+        // rotation relative to its own center, not the lattice origin.
+        let rect = Rect::new(Point(5, 5), Size(3, 3));
+        assert_eq!(Point(6, 6), rect.transform_point(Point(6, 6), ROT_CCW180));
+        assert_eq!(Point(7, 5), rect.transform_point(Point(5, 7), ROT_CCW180));
+    }
 }
@@ -0,0 +1,320 @@
+//! Geometric types for a triangular lattice, where each cell is an upward or
+//! downward triangle addressed by its three half-plane indices.
+//!
+//! This mirrors the square-lattice `Point`/`Move`/`Table` API at the top of
+//! the crate without disturbing it.
+
+use std::ops::{Add, Sub};
+
+/// A triangular-lattice coordinate, given as the three half-plane indices
+/// that bound the cell.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Tri(pub i32, pub i32, pub i32);
+
+/// The orientation of a triangular cell.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TriOrientation {
+    /// An upward-pointing triangle.
+    Up,
+    /// A downward-pointing triangle.
+    Down,
+}
+
+/// A difference between two `Tri` coordinates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct TriMove(pub i32, pub i32, pub i32);
+
+/// The edge vector toward the neighbor sharing the `a` side.
+pub const TRI_EDGE_A: TriMove = TriMove(1, 0, 0);
+
+/// The edge vector toward the neighbor sharing the `b` side.
+pub const TRI_EDGE_B: TriMove = TriMove(0, 1, 0);
+
+/// The edge vector toward the neighbor sharing the `c` side.
+pub const TRI_EDGE_C: TriMove = TriMove(0, 0, 1);
+
+/// `TriMove` vectors toward the three edge-adjacent cells.
+pub const TRI_EDGES: [TriMove; 3] = [TRI_EDGE_A, TRI_EDGE_B, TRI_EDGE_C];
+
+impl Add<TriMove> for Tri {
+    type Output = Tri;
+
+    #[inline]
+    fn add(self, other: TriMove) -> Tri {
+        Tri(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    }
+}
+
+impl Sub<TriMove> for Tri {
+    type Output = Tri;
+
+    #[inline]
+    fn sub(self, other: TriMove) -> Tri {
+        Tri(self.0 - other.0, self.1 - other.1, self.2 - other.2)
+    }
+}
+
+impl Sub<Tri> for Tri {
+    type Output = TriMove;
+
+    #[inline]
+    fn sub(self, other: Tri) -> TriMove {
+        TriMove(self.0 - other.0, self.1 - other.1, self.2 - other.2)
+    }
+}
+
+impl Tri {
+    /// Returns the orientation of this cell: upward when `a + b + c` is
+    /// even, downward when it is odd.
+    #[inline]
+    pub fn orientation(self) -> TriOrientation {
+        if (self.0 + self.1 + self.2).rem_euclid(2) == 0 {
+            TriOrientation::Up
+        } else {
+            TriOrientation::Down
+        }
+    }
+
+    /// Returns true if this is an upward-pointing triangle.
+    #[inline]
+    pub fn is_up(self) -> bool {
+        self.orientation() == TriOrientation::Up
+    }
+
+    /// Returns true if this is a downward-pointing triangle.
+    #[inline]
+    pub fn is_down(self) -> bool {
+        self.orientation() == TriOrientation::Down
+    }
+
+    /// Returns an iterator over the cells sharing an edge with this one.
+    ///
+    /// An upward triangle's neighbors lie across its three sides at `self +
+    /// edge`; a downward triangle's lie at `self - edge`, since crossing
+    /// any edge always flips the orientation.
+    #[inline]
+    pub fn neighbors(self) -> TriNeighbors {
+        TriNeighbors {
+            tri: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over the edge-adjacent neighbors of a `Tri` cell.
+#[derive(Clone, Debug)]
+pub struct TriNeighbors {
+    tri: Tri,
+    index: usize,
+}
+
+impl Iterator for TriNeighbors {
+    type Item = Tri;
+
+    #[inline]
+    fn next(&mut self) -> Option<Tri> {
+        let edge = *TRI_EDGES.get(self.index)?;
+        self.index += 1;
+        Some(match self.tri.orientation() {
+            TriOrientation::Up => self.tri + edge,
+            TriOrientation::Down => self.tri - edge,
+        })
+    }
+}
+
+/// Returns the dense index of row `r`'s first cell.
+///
+/// Row `r` is preceded by `r` rows holding `2 * i + 1` cells each, which
+/// sums to `r * r`.
+#[inline]
+fn row_offset(r: i32) -> i32 {
+    r * r
+}
+
+/// Converts a `Tri` coordinate to its dense cell index on a triangular
+/// board of the given `order` (number of rows), or `None` if the cell lies
+/// outside the board.
+///
+/// The row index is taken directly as `c` (rather than, say, `order - 1 -
+/// c`), so that `a + b + c` is `2 * c` for an upward cell and `2 * c + 1`
+/// for a downward one: the parity `Tri::orientation` reads off a coordinate
+/// never depends on which `order` board it happens to be placed on.
+#[inline]
+pub fn tri_to_index(order: i32, t: Tri) -> Option<usize> {
+    let Tri(a, b, c) = t;
+    if a < 0 || b < 0 || c < 0 {
+        return None;
+    }
+    let r = c;
+    if r > order - 1 {
+        return None;
+    }
+    if a + b == r {
+        Some((row_offset(r) + 2 * a) as usize)
+    } else if a + b == r + 1 && a >= 1 && b >= 1 {
+        Some((row_offset(r) + 2 * (a - 1) + 1) as usize)
+    } else {
+        None
+    }
+}
+
+/// Converts a dense cell index on a triangular board of the given `order`
+/// back to its `Tri` coordinate, or `None` if the index is out of range.
+#[inline]
+pub fn index_to_tri(order: i32, index: usize) -> Option<Tri> {
+    if index >= (order * order) as usize {
+        return None;
+    }
+    let idx = index as i32;
+    let r = crate::isqrt(i64::from(idx)) as i32;
+    let j = idx - row_offset(r);
+    let c = r;
+    if j % 2 == 0 {
+        let a = j / 2;
+        Some(Tri(a, r - a, c))
+    } else {
+        let a = (j - 1) / 2 + 1;
+        Some(Tri(a, r - (a - 1), c))
+    }
+}
+
+/// A dense storage table over a triangular board, mirroring `Table` for the
+/// square lattice.
+///
+/// The board is the triangular region of `order` rows addressed by `Tri`
+/// coordinates with `a, b, c >= 0`, `c < order` giving the row from the
+/// top, and `a + b` equal to `c` (the upward cells) or `c + 1` (the
+/// downward cells).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TriTable<T> {
+    order: i32,
+    data: Vec<T>,
+}
+
+impl<T> TriTable<T> {
+    /// Creates a new empty table of the given `order`, filled with `init`.
+    #[inline]
+    pub fn new_empty(order: i32, init: T) -> TriTable<T>
+    where
+        T: Clone,
+    {
+        TriTable {
+            order: order,
+            data: vec![init; (order * order) as usize],
+        }
+    }
+
+    /// Returns the number of rows of the board.
+    #[inline]
+    pub fn order(&self) -> i32 {
+        self.order
+    }
+
+    /// Returns a reference to the cell at `t`, or `None` if `t` is outside
+    /// the board.
+    #[inline]
+    pub fn get(&self, t: Tri) -> Option<&T> {
+        tri_to_index(self.order, t).map(|idx| &self.data[idx])
+    }
+
+    /// Returns a mutable reference to the cell at `t`, or `None` if `t` is
+    /// outside the board.
+    #[inline]
+    pub fn get_mut(&mut self, t: Tri) -> Option<&mut T> {
+        tri_to_index(self.order, t).map(move |idx| &mut self.data[idx])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orientation() {
+        assert_eq!(TriOrientation::Up, Tri(0, 0, 0).orientation());
+        assert_eq!(TriOrientation::Down, Tri(1, 0, 0).orientation());
+        assert_eq!(TriOrientation::Down, Tri(0, 0, 1).orientation());
+        assert_eq!(TriOrientation::Up, Tri(1, 1, 0).orientation());
+        assert!(Tri(0, 0, 0).is_up());
+        assert!(Tri(1, 0, 0).is_down());
+    }
+
+    #[test]
+    fn neighbors() {
+        let up = Tri(1, 1, 0);
+        assert_eq!(
+            vec![Tri(2, 1, 0), Tri(1, 2, 0), Tri(1, 1, 1)],
+            up.neighbors().collect::<Vec<_>>()
+        );
+        let down = Tri(1, 0, 0);
+        assert_eq!(
+            vec![Tri(0, 0, 0), Tri(1, -1, 0), Tri(1, 0, -1)],
+            down.neighbors().collect::<Vec<_>>()
+        );
+        for n in up.neighbors() {
+            assert_eq!(TriOrientation::Down, n.orientation());
+        }
+        for n in down.neighbors() {
+            assert_eq!(TriOrientation::Up, n.orientation());
+        }
+    }
+
+    #[test]
+    fn row_aligned_orientation_is_independent_of_order() {
+        // A row-aligned ("up") cell must read back as `Up`, and the
+        // adjoining ("down") cell as `Down`, no matter which `order` board
+        // it happens to live on.
+        for order in 1..=5 {
+            for r in 0..order {
+                let up = Tri(0, r, r);
+                assert_eq!(
+                    TriOrientation::Up,
+                    up.orientation(),
+                    "order={}, r={}",
+                    order,
+                    r
+                );
+                assert!(tri_to_index(order, up).is_some());
+                if r >= 1 {
+                    let down = Tri(1, r, r);
+                    assert_eq!(
+                        TriOrientation::Down,
+                        down.orientation(),
+                        "order={}, r={}",
+                        order,
+                        r
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tri_index_roundtrip() {
+        for order in 1..=6 {
+            let len = (order * order) as usize;
+            for index in 0..len {
+                let t = index_to_tri(order, index).unwrap();
+                assert_eq!(Some(index), tri_to_index(order, t));
+            }
+        }
+    }
+
+    #[test]
+    fn tri_to_index_out_of_range() {
+        assert_eq!(None, tri_to_index(3, Tri(-1, 0, 0)));
+        assert_eq!(None, tri_to_index(3, Tri(0, 0, 3)));
+        assert_eq!(None, index_to_tri(3, 9));
+    }
+
+    #[test]
+    fn tri_table_get() {
+        let mut table = TriTable::new_empty(3, 0);
+        assert_eq!(3, table.order());
+        let t = Tri(0, 0, 0);
+        assert_eq!(Some(&0), table.get(t));
+        *table.get_mut(t).unwrap() = 42;
+        assert_eq!(Some(&42), table.get(t));
+        assert_eq!(None, table.get(Tri(0, 0, 3)));
+    }
+}